@@ -0,0 +1,169 @@
+use super::{AnalysisResult, Finding, Severity};
+use anyhow::Result;
+use ignore::WalkBuilder;
+use std::fs::Metadata;
+use std::path::Path;
+
+/// Anything over this size is flagged as a likely media/model asset, even when
+/// it isn't executable and doesn't look binary by content.
+const LARGE_BLOB_THRESHOLD_BYTES: u64 = 5 * 1024 * 1024;
+
+const BUILD_OUTPUT_DIRS: &[&str] = &["target", "dist", "node_modules", "hfuzz_target"];
+const SOURCE_EXTENSIONS: &[&str] = &["rs", "py", "sh", "js"];
+
+/// Runs on every repository regardless of detected tech stack - committed
+/// binaries and build artifacts are a problem in any language.
+pub async fn analyze(owner: &str, repo: &str) -> Result<AnalysisResult> {
+    let workdir = super::clone_repo(owner, repo)?;
+    let findings = scan(workdir.path());
+
+    Ok(AnalysisResult {
+        category: "Repository Hygiene".to_string(),
+        findings,
+        tech_stack: "general".to_string(),
+    })
+}
+
+fn scan(root: &Path) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    // `ignore::WalkBuilder` skips `.gitignore`d paths by default, so anything it
+    // yields is actually tracked (or trackable) in version control. `hidden(false)`
+    // would otherwise also walk `.git` itself, so that directory is filtered out.
+    let walker = WalkBuilder::new(root)
+        .hidden(false)
+        .filter_entry(|e| e.file_name() != ".git")
+        .build();
+
+    for entry in walker.filter_map(Result::ok) {
+        let path = entry.path();
+        if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            continue;
+        }
+        if is_in_build_output_dir(root, path) || has_source_extension(path) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        if let Some(description) = classify(path, &metadata) {
+            let location = path.strip_prefix(root).unwrap_or(path).display().to_string();
+            findings.push(Finding {
+                severity: Severity::High,
+                location,
+                description,
+                optimization: Some(
+                    "Add a .gitignore entry for this path, then rewrite history with \
+                     `git filter-repo` or move the blob to Git LFS"
+                        .to_string(),
+                ),
+            });
+        }
+    }
+
+    findings
+}
+
+fn is_in_build_output_dir(root: &Path, path: &Path) -> bool {
+    path.strip_prefix(root)
+        .into_iter()
+        .flat_map(|rel| rel.components())
+        .any(|c| BUILD_OUTPUT_DIRS.contains(&c.as_os_str().to_string_lossy().as_ref()))
+}
+
+fn has_source_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|ext| SOURCE_EXTENSIONS.contains(&ext))
+        .unwrap_or(false)
+}
+
+fn classify(path: &Path, metadata: &Metadata) -> Option<String> {
+    let size = metadata.len();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 != 0 {
+            return Some(format!("Tracked executable file ({size} bytes)"));
+        }
+    }
+
+    if size > LARGE_BLOB_THRESHOLD_BYTES {
+        return Some(format!(
+            "Large blob ({size} bytes) likely a committed media/model asset"
+        ));
+    }
+
+    let contents = std::fs::read(path).ok()?;
+    if looks_binary(&contents) {
+        return Some(format!("Binary file ({size} bytes) detected by content sniffing"));
+    }
+
+    None
+}
+
+/// Known binary magic numbers, falling back to a null-byte sniff for anything else.
+/// `& 0o111 != 0` handles the common "someone committed a compiled binary" case on
+/// Unix; this covers platforms/contexts where mode bits aren't meaningful.
+fn looks_binary(bytes: &[u8]) -> bool {
+    const MAGIC_NUMBERS: &[&[u8]] = &[
+        b"\x7fELF",           // Linux ELF executable/shared object
+        b"MZ",                // Windows PE
+        b"\xCA\xFE\xBA\xBE",  // Mach-O fat binary / Java class
+        b"\xFE\xED\xFA\xCE",  // Mach-O 32-bit
+        b"\xFE\xED\xFA\xCF",  // Mach-O 64-bit
+        b"PK\x03\x04",        // zip/jar/wheel archive
+        b"\x89PNG\r\n\x1a\n", // PNG
+        b"GIF8",              // GIF
+        b"\xFF\xD8\xFF",      // JPEG
+    ];
+
+    if MAGIC_NUMBERS.iter().any(|magic| bytes.starts_with(magic)) {
+        return true;
+    }
+
+    let sniff_len = bytes.len().min(8192);
+    bytes[..sniff_len].contains(&0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_elf_magic() {
+        let mut bytes = b"\x7fELF".to_vec();
+        bytes.extend_from_slice(&[0u8; 16]);
+        assert!(looks_binary(&bytes));
+    }
+
+    #[test]
+    fn detects_null_byte_blobs_without_known_magic() {
+        assert!(looks_binary(b"not quite text\0but close"));
+    }
+
+    #[test]
+    fn plain_text_is_not_binary() {
+        assert!(!looks_binary(b"fn main() {}\n"));
+    }
+
+    #[test]
+    fn source_extensions_are_skipped() {
+        assert!(has_source_extension(Path::new("src/main.rs")));
+        assert!(has_source_extension(Path::new("scripts/deploy.sh")));
+        assert!(!has_source_extension(Path::new("assets/model.bin")));
+    }
+
+    #[test]
+    fn build_output_dirs_are_skipped() {
+        let root = Path::new("/repo");
+        assert!(is_in_build_output_dir(root, Path::new("/repo/target/debug/app")));
+        assert!(is_in_build_output_dir(
+            root,
+            Path::new("/repo/services/web/node_modules/pkg/index.js")
+        ));
+        assert!(!is_in_build_output_dir(root, Path::new("/repo/src/main.rs")));
+    }
+}
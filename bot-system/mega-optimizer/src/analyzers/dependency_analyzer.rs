@@ -1,19 +1,466 @@
 use super::{AnalysisResult, Finding, Severity};
-use anyhow::Result;
+use anyhow::{Context, Result};
+use ignore::WalkBuilder;
+use log::warn;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Mirrors `binary_analyzer`'s list: these directories are either vendored
+/// dependencies or build output, never hand-written manifests worth resolving.
+const BUILD_OUTPUT_DIRS: &[&str] = &["target", "dist", "node_modules", "hfuzz_target"];
+
+/// Interns package names to small integer ids so the dependency graph doesn't
+/// clone the same package name once per (service, manifest) edge.
+#[derive(Default)]
+struct Interner {
+    ids: HashMap<String, PackageId>,
+    names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct PackageId(usize);
+
+impl Interner {
+    fn intern(&mut self, name: &str) -> PackageId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = PackageId(self.names.len());
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+
+    fn name(&self, id: PackageId) -> &str {
+        &self.names[id.0]
+    }
+}
+
+/// One `(package, version-constraint)` edge contributed by a single manifest.
+struct Requirement {
+    package: PackageId,
+    constraint: String,
+    service: String,
+    manifest: &'static str,
+}
+
+enum Constraint {
+    /// No constraint at all (`*`, missing, or blank).
+    Any,
+    /// An exact pin, either from `=`/`==` in a manifest or a lockfile entry.
+    Exact(String),
+    /// A range (`^1.2`, `~=1.0`, `>=1.0,<2.0`, a bare `1.2.3` in Cargo.toml, ...).
+    Range(String),
+}
+
+pub async fn analyze(owner: &str, repo: &str) -> Result<AnalysisResult> {
+    let workdir = super::clone_repo(owner, repo)?;
+
+    let mut interner = Interner::default();
+    let mut requirements = Vec::new();
+    collect_requirements(workdir.path(), &mut interner, &mut requirements)?;
+
+    let findings = resolve(&interner, &requirements);
 
-pub async fn analyze(_owner: &str, _repo: &str) -> Result<AnalysisResult> {
-    let mut findings = Vec::new();
-    
-    findings.push(Finding {
-        severity: Severity::Medium,
-        location: "services/*/requirements.txt".to_string(),
-        description: "Unpinned dependency versions detected".to_string(),
-        optimization: Some("Pin all dependencies to specific versions for reproducibility".to_string()),
-    });
-    
     Ok(AnalysisResult {
         category: "Dependency Management".to_string(),
         findings,
         tech_stack: "general".to_string(),
     })
 }
+
+fn collect_requirements(
+    root: &Path,
+    interner: &mut Interner,
+    requirements: &mut Vec<Requirement>,
+) -> Result<()> {
+    // `ignore::WalkBuilder` honors `.gitignore` (and always skips `.git`) the same
+    // way `binary_analyzer` does, so vendored trees like `node_modules` don't get
+    // scanned for manifests in the first place.
+    let walker = WalkBuilder::new(root)
+        .hidden(false)
+        .filter_entry(|e| e.file_name() != ".git")
+        .build();
+
+    for entry in walker.filter_map(Result::ok) {
+        let path = entry.path();
+        if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            continue;
+        }
+        if is_in_build_output_dir(root, path) {
+            continue;
+        }
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let service = service_name(root, path);
+
+        // A single malformed manifest anywhere in the tree shouldn't abort the
+        // whole pass - log it and keep resolving every other manifest.
+        let result = match file_name {
+            "Cargo.toml" => parse_cargo_toml(path, &service, interner, requirements),
+            "Cargo.lock" => parse_toml_lockfile(path, &service, "Cargo.lock", interner, requirements),
+            "requirements.txt" => parse_requirements_txt(path, &service, interner, requirements),
+            "poetry.lock" => parse_toml_lockfile(path, &service, "poetry.lock", interner, requirements),
+            "package.json" => parse_package_json(path, &service, interner, requirements),
+            "package-lock.json" => parse_package_lock_json(path, &service, interner, requirements),
+            _ => Ok(()),
+        };
+        if let Err(err) = result {
+            warn!("skipping unparseable manifest {}: {err:#}", path.display());
+        }
+    }
+    Ok(())
+}
+
+fn is_in_build_output_dir(root: &Path, path: &Path) -> bool {
+    path.strip_prefix(root)
+        .into_iter()
+        .flat_map(|rel| rel.components())
+        .any(|c| BUILD_OUTPUT_DIRS.contains(&c.as_os_str().to_string_lossy().as_ref()))
+}
+
+/// The manifest's parent directory relative to the repo root, e.g.
+/// `services/image-generation` for `services/image-generation/package.json`.
+fn service_name(root: &Path, manifest_path: &Path) -> String {
+    manifest_path
+        .strip_prefix(root)
+        .ok()
+        .and_then(|rel| rel.parent())
+        .map(|p| p.to_string_lossy().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| ".".to_string())
+}
+
+fn parse_cargo_toml(
+    path: &Path,
+    service: &str,
+    interner: &mut Interner,
+    requirements: &mut Vec<Requirement>,
+) -> Result<()> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let doc: toml::Value = text.parse().with_context(|| format!("parsing {}", path.display()))?;
+
+    for section in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        let Some(table) = doc.get(section).and_then(toml::Value::as_table) else {
+            continue;
+        };
+        for (name, value) in table {
+            let constraint = match value {
+                toml::Value::String(v) => v.clone(),
+                toml::Value::Table(t) => t
+                    .get("version")
+                    .and_then(toml::Value::as_str)
+                    .unwrap_or("*")
+                    .to_string(),
+                _ => "*".to_string(),
+            };
+            requirements.push(Requirement {
+                package: interner.intern(name),
+                constraint,
+                service: service.to_string(),
+                manifest: "Cargo.toml",
+            });
+        }
+    }
+    Ok(())
+}
+
+/// `Cargo.lock` and `poetry.lock` share the same `[[package]] name = "..", version = ".."` shape.
+fn parse_toml_lockfile(
+    path: &Path,
+    service: &str,
+    manifest: &'static str,
+    interner: &mut Interner,
+    requirements: &mut Vec<Requirement>,
+) -> Result<()> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let doc: toml::Value = text.parse().with_context(|| format!("parsing {}", path.display()))?;
+
+    let Some(packages) = doc.get("package").and_then(toml::Value::as_array) else {
+        return Ok(());
+    };
+    for pkg in packages {
+        let (Some(name), Some(version)) = (
+            pkg.get("name").and_then(toml::Value::as_str),
+            pkg.get("version").and_then(toml::Value::as_str),
+        ) else {
+            continue;
+        };
+        requirements.push(Requirement {
+            package: interner.intern(name),
+            constraint: format!("={version}"),
+            service: service.to_string(),
+            manifest,
+        });
+    }
+    Ok(())
+}
+
+fn parse_requirements_txt(
+    path: &Path,
+    service: &str,
+    interner: &mut Interner,
+    requirements: &mut Vec<Requirement>,
+) -> Result<()> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (name, constraint) = match line.find(|c: char| "=<>~!".contains(c)) {
+            Some(i) => (line[..i].trim(), line[i..].trim()),
+            None => (line, "*"),
+        };
+        if name.is_empty() {
+            continue;
+        }
+        requirements.push(Requirement {
+            package: interner.intern(name),
+            constraint: constraint.to_string(),
+            service: service.to_string(),
+            manifest: "requirements.txt",
+        });
+    }
+    Ok(())
+}
+
+fn parse_package_json(
+    path: &Path,
+    service: &str,
+    interner: &mut Interner,
+    requirements: &mut Vec<Requirement>,
+) -> Result<()> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let doc: serde_json::Value =
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))?;
+
+    for section in ["dependencies", "devDependencies"] {
+        let Some(deps) = doc.get(section).and_then(serde_json::Value::as_object) else {
+            continue;
+        };
+        for (name, value) in deps {
+            let constraint = value.as_str().unwrap_or("*").to_string();
+            requirements.push(Requirement {
+                package: interner.intern(name),
+                constraint,
+                service: service.to_string(),
+                manifest: "package.json",
+            });
+        }
+    }
+    Ok(())
+}
+
+fn parse_package_lock_json(
+    path: &Path,
+    service: &str,
+    interner: &mut Interner,
+    requirements: &mut Vec<Requirement>,
+) -> Result<()> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let doc: serde_json::Value =
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))?;
+
+    // npm v7+ lockfile shape: a flat "packages" map keyed by "node_modules/<name>".
+    if let Some(packages) = doc.get("packages").and_then(serde_json::Value::as_object) {
+        for (key, value) in packages {
+            let Some(name) = key.strip_prefix("node_modules/") else {
+                continue;
+            };
+            let Some(version) = value.get("version").and_then(serde_json::Value::as_str) else {
+                continue;
+            };
+            requirements.push(Requirement {
+                package: interner.intern(name),
+                constraint: format!("={version}"),
+                service: service.to_string(),
+                manifest: "package-lock.json",
+            });
+        }
+        return Ok(());
+    }
+
+    // Legacy (npm v5/v6) lockfile shape: nested "dependencies" map keyed by name.
+    if let Some(deps) = doc.get("dependencies").and_then(serde_json::Value::as_object) {
+        for (name, value) in deps {
+            let Some(version) = value.get("version").and_then(serde_json::Value::as_str) else {
+                continue;
+            };
+            requirements.push(Requirement {
+                package: interner.intern(name),
+                constraint: format!("={version}"),
+                service: service.to_string(),
+                manifest: "package-lock.json",
+            });
+        }
+    }
+    Ok(())
+}
+
+fn classify(raw: &str) -> Constraint {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() || trimmed == "*" {
+        return Constraint::Any;
+    }
+    if let Some(v) = trimmed.strip_prefix("==").or_else(|| trimmed.strip_prefix('=')) {
+        return Constraint::Exact(v.trim().to_string());
+    }
+    Constraint::Range(trimmed.to_string())
+}
+
+/// Pulls the leading major-version digits out of a constraint, e.g. `"^1.2.3"` -> `1`,
+/// `">=2.0,<3"` -> `2`. Used as a cheap stand-in for full semver-range intersection.
+fn major_version(constraint: &str) -> Option<u64> {
+    let start = constraint.find(|c: char| c.is_ascii_digit())?;
+    let rest = &constraint[start..];
+    let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+/// Runs the constraint-satisfaction pass: group requirements by package, and for
+/// each package compute whether every service's request can be satisfied together.
+fn resolve(interner: &Interner, requirements: &[Requirement]) -> Vec<Finding> {
+    let mut by_package: HashMap<PackageId, Vec<&Requirement>> = HashMap::new();
+    for req in requirements {
+        by_package.entry(req.package).or_default().push(req);
+    }
+
+    let start = Instant::now();
+    let total = by_package.len();
+    let mut progress_shown = false;
+    let mut findings = Vec::new();
+
+    for (i, (package, reqs)) in by_package.into_iter().enumerate() {
+        if !progress_shown && start.elapsed() >= Duration::from_millis(500) {
+            println!("resolving {} packages...", total - i);
+            progress_shown = true;
+        }
+        if let Some(finding) = resolve_package(interner.name(package), &reqs) {
+            findings.push(finding);
+        }
+    }
+
+    findings
+}
+
+fn resolve_package(name: &str, reqs: &[&Requirement]) -> Option<Finding> {
+    let mut exact_versions: HashMap<String, Vec<String>> = HashMap::new();
+    let mut majors: HashSet<u64> = HashSet::new();
+    let mut any_unpinned = false;
+    let mut locations = Vec::new();
+
+    for req in reqs {
+        locations.push(format!("{}/{}", req.service, req.manifest));
+        match classify(&req.constraint) {
+            Constraint::Any => any_unpinned = true,
+            Constraint::Exact(version) => {
+                if let Some(major) = major_version(&version) {
+                    majors.insert(major);
+                }
+                exact_versions
+                    .entry(version)
+                    .or_default()
+                    .push(req.service.clone());
+            }
+            Constraint::Range(range) => {
+                if let Some(major) = major_version(&range) {
+                    majors.insert(major);
+                }
+            }
+        }
+    }
+
+    let location = locations.join(", ");
+
+    if exact_versions.len() > 1 {
+        let mut pins: Vec<(String, Vec<String>)> = exact_versions.into_iter().collect();
+        pins.sort_by(|a, b| a.0.cmp(&b.0));
+        let highest = pins.last().map(|(v, _)| v.clone()).unwrap_or_default();
+        let detail = pins
+            .iter()
+            .map(|(version, services)| format!("{} pin {version}", services.join(", ")))
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Some(Finding {
+            severity: Severity::High,
+            location,
+            description: format!("'{name}' is pinned to conflicting exact versions: {detail}"),
+            optimization: Some(format!("Align every service on '{name}' {highest}")),
+        });
+    }
+
+    if majors.len() > 1 {
+        let mut majors: Vec<u64> = majors.into_iter().collect();
+        majors.sort_unstable();
+        return Some(Finding {
+            severity: Severity::Critical,
+            location,
+            description: format!(
+                "'{name}' has no version satisfying every service (requested majors: {majors:?})"
+            ),
+            optimization: Some(format!(
+                "Upgrade the services pinned to an older major of '{name}' to {}",
+                majors.last().unwrap()
+            )),
+        });
+    }
+
+    if any_unpinned {
+        return Some(Finding {
+            severity: Severity::Medium,
+            location,
+            description: format!("'{name}' has an unpinned or wildcard version requirement"),
+            optimization: Some(format!("Pin '{name}' to a specific compatible version")),
+        });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn req(package: PackageId, constraint: &str, service: &str, manifest: &'static str) -> Requirement {
+        Requirement {
+            package,
+            constraint: constraint.to_string(),
+            service: service.to_string(),
+            manifest,
+        }
+    }
+
+    #[test]
+    fn flags_conflicting_exact_pins_as_high() {
+        let a = req(PackageId(0), "=1.2.3", "service-a", "Cargo.lock");
+        let b = req(PackageId(0), "=2.0.0", "service-b", "Cargo.lock");
+        let finding = resolve_package("serde", &[&a, &b]).unwrap();
+        assert!(matches!(finding.severity, Severity::High));
+    }
+
+    #[test]
+    fn flags_unresolvable_major_split_as_critical() {
+        let a = req(PackageId(0), "^1.0", "service-a", "Cargo.toml");
+        let b = req(PackageId(0), "^2.0", "service-b", "Cargo.toml");
+        let finding = resolve_package("serde", &[&a, &b]).unwrap();
+        assert!(matches!(finding.severity, Severity::Critical));
+    }
+
+    #[test]
+    fn flags_wildcard_as_medium() {
+        let a = req(PackageId(0), "*", "service-a", "requirements.txt");
+        let finding = resolve_package("requests", &[&a]).unwrap();
+        assert!(matches!(finding.severity, Severity::Medium));
+    }
+
+    #[test]
+    fn consistent_pins_produce_no_finding() {
+        let a = req(PackageId(0), "=1.2.3", "service-a", "Cargo.lock");
+        let b = req(PackageId(0), "=1.2.3", "service-b", "Cargo.lock");
+        assert!(resolve_package("serde", &[&a, &b]).is_none());
+    }
+}
@@ -2,9 +2,12 @@ pub mod rust_analyzer;
 pub mod python_analyzer;
 pub mod docker_analyzer;
 pub mod dependency_analyzer;
+pub mod binary_analyzer;
 
+use anyhow::{ensure, Context, Result};
 use serde::{Deserialize, Serialize};
-use anyhow::Result;
+use std::process::Command;
+use tempfile::TempDir;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TechStack {
@@ -38,6 +41,22 @@ pub enum Severity {
     Low,
 }
 
+/// Shallow-clones `owner/repo` into a fresh temp dir so analyzers can run
+/// local tools (`cargo clippy`, manifest parsers, ...) against it.
+pub(crate) fn clone_repo(owner: &str, repo: &str) -> Result<TempDir> {
+    let dir = tempfile::tempdir().context("failed to create temp clone dir")?;
+    let url = format!("https://github.com/{owner}/{repo}.git");
+
+    let status = Command::new("git")
+        .args(["clone", "--depth", "1", &url])
+        .arg(dir.path())
+        .status()
+        .context("failed to spawn git clone")?;
+    ensure!(status.success(), "git clone failed for {url}");
+
+    Ok(dir)
+}
+
 pub async fn detect_tech_stack(_owner: &str, _repo: &str) -> Result<TechStack> {
     // In a real implementation, this would scan the repository
     // For now, return a comprehensive tech stack
@@ -1,34 +1,253 @@
 use super::{AnalysisResult, Finding, Severity};
-use anyhow::Result;
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde_json::Value;
+use std::path::Path;
+use std::process::Command;
+
+/// Primary "problem matcher" line: `warning[lint::code]: message` or `error: message`.
+const DIAGNOSTIC_HEADER_RE: &str =
+    r"^(?:\x1b\[[\d;]+m)*(warning|warn|error)(?:\x1b\[[\d;]+m)*(?:\[(.*?)\])?:\s*(.*)$";
+/// Secondary line immediately following a header: `--> src/foo.rs:12:5`.
+const DIAGNOSTIC_LOCATION_RE: &str = r"^\s*-->\s*(.+):(\d+):(\d+)$";
+/// `cargo fmt --check` writes `Diff in src/foo.rs:12:` followed by a unified diff, to stdout.
+const FMT_DIFF_HEADER_RE: &str = r"^Diff in (.+):(\d+):$";
+
+pub async fn analyze(owner: &str, repo: &str) -> Result<AnalysisResult> {
+    let workdir = super::clone_repo(owner, repo)?;
+
+    let mut findings = run_clippy(workdir.path())?;
+    findings.extend(run_fmt_check(workdir.path())?);
 
-pub async fn analyze(_owner: &str, _repo: &str) -> Result<AnalysisResult> {
-    let mut findings = Vec::new();
-    
-    // Check for common Rust optimization opportunities
-    findings.push(Finding {
-        severity: Severity::High,
-        location: "Cargo.toml".to_string(),
-        description: "Missing LTO (Link Time Optimization) in release profile".to_string(),
-        optimization: Some("Add lto = true in [profile.release]".to_string()),
-    });
-    
-    findings.push(Finding {
-        severity: Severity::Medium,
-        location: "src/**/*.rs".to_string(),
-        description: "Consider using SIMD vectorization for parallel operations".to_string(),
-        optimization: Some("Use std::simd or external crates like packed_simd".to_string()),
-    });
-    
-    findings.push(Finding {
-        severity: Severity::Medium,
-        location: "src/**/*.rs".to_string(),
-        description: "Potential for zero-copy deserialization".to_string(),
-        optimization: Some("Use serde_zero_copy for large data structures".to_string()),
-    });
-    
     Ok(AnalysisResult {
         category: "Rust Performance".to_string(),
         findings,
         tech_stack: "rust".to_string(),
     })
 }
+
+fn run_clippy(repo_path: &Path) -> Result<Vec<Finding>> {
+    let output = Command::new("cargo")
+        .args(["clippy", "--message-format=json"])
+        .current_dir(repo_path)
+        .output()
+        .context("failed to run cargo clippy")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let findings: Vec<Finding> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter_map(|msg| finding_from_clippy_json(&msg))
+        .collect();
+
+    // `--message-format=json` may not be understood (older cargo, or the repo has no
+    // Cargo.toml at all) - fall back to scraping the human-readable diagnostics.
+    if findings.is_empty() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Ok(parse_text_diagnostics(&stderr));
+    }
+
+    Ok(findings)
+}
+
+fn run_fmt_check(repo_path: &Path) -> Result<Vec<Finding>> {
+    let output = Command::new("cargo")
+        .args(["fmt", "--check"])
+        .current_dir(repo_path)
+        .output()
+        .context("failed to run cargo fmt --check")?;
+
+    // Unlike clippy, `cargo fmt --check` writes its `Diff in <file>:<line>:` report to
+    // stdout (stderr is reserved for rustfmt's own errors), so this needs its own parser -
+    // the clippy/compiler "warning:"/"-->" matcher never matches rustfmt's output shape.
+    Ok(parse_fmt_diagnostics(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn finding_from_clippy_json(message: &Value) -> Option<Finding> {
+    let diag = message.get("message")?;
+    let level = diag.get("level")?.as_str()?;
+    let severity = match level {
+        "error" => Severity::Critical,
+        "warning" => Severity::Medium,
+        _ => return None,
+    };
+
+    let description = diag.get("message")?.as_str()?.to_string();
+    let code = diag
+        .get("code")
+        .and_then(|c| c.get("code"))
+        .and_then(|c| c.as_str());
+
+    let span = diag
+        .get("spans")?
+        .as_array()?
+        .iter()
+        .find(|s| s.get("is_primary").and_then(Value::as_bool).unwrap_or(false))?;
+    let file = span.get("file_name")?.as_str()?;
+    let line = span.get("line_start")?.as_u64()?;
+    let column = span.get("column_start")?.as_u64()?;
+
+    let help = diag
+        .get("children")
+        .and_then(Value::as_array)
+        .and_then(|children| {
+            children
+                .iter()
+                .find(|c| c.get("level").and_then(Value::as_str) == Some("help"))
+        })
+        .and_then(|c| c.get("message"))
+        .and_then(Value::as_str);
+
+    Some(Finding {
+        severity,
+        location: format!("{file}:{line}:{column}"),
+        description: description.clone(),
+        optimization: Some(format_optimization(code, help, &description)),
+    })
+}
+
+/// Two-state "problem matcher": a diagnostic header line followed by its `-->` location line,
+/// mirroring the approach editors use to turn compiler output into clickable problems.
+fn parse_text_diagnostics(text: &str) -> Vec<Finding> {
+    let header_re = Regex::new(DIAGNOSTIC_HEADER_RE).expect("valid regex");
+    let location_re = Regex::new(DIAGNOSTIC_LOCATION_RE).expect("valid regex");
+    let clean = strip_ansi(text);
+    let lines: Vec<&str> = clean.lines().collect();
+
+    let mut findings = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(header) = header_re.captures(lines[i]) else {
+            i += 1;
+            continue;
+        };
+
+        let severity = match &header[1] {
+            "error" => Severity::Critical,
+            _ => Severity::Medium,
+        };
+        let code = header.get(2).map(|m| m.as_str().to_string());
+        let description = header[3].to_string();
+
+        let mut location = "unknown".to_string();
+        if let Some(loc) = lines.get(i + 1).and_then(|l| location_re.captures(l)) {
+            location = format!("{}:{}:{}", &loc[1], &loc[2], &loc[3]);
+            i += 1;
+        }
+
+        let help = lines
+            .iter()
+            .skip(i + 1)
+            .take(4)
+            .find_map(|l| l.trim_start().strip_prefix("= help:"))
+            .map(|s| s.trim().to_string());
+
+        findings.push(Finding {
+            severity,
+            location,
+            description: description.clone(),
+            optimization: Some(format_optimization(code.as_deref(), help.as_deref(), &description)),
+        });
+
+        i += 1;
+    }
+    findings
+}
+
+/// Parses `cargo fmt --check` output: a `Diff in <file>:<line>:` header followed by a
+/// unified diff body, repeated once per misformatted file.
+fn parse_fmt_diagnostics(text: &str) -> Vec<Finding> {
+    let header_re = Regex::new(FMT_DIFF_HEADER_RE).expect("valid regex");
+    let lines: Vec<&str> = text.lines().collect();
+
+    let mut findings = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(header) = header_re.captures(lines[i]) else {
+            i += 1;
+            continue;
+        };
+        let file = header[1].to_string();
+        let line = header[2].to_string();
+
+        let mut j = i + 1;
+        let mut diff_body = Vec::new();
+        while j < lines.len() && !header_re.is_match(lines[j]) {
+            diff_body.push(lines[j]);
+            j += 1;
+        }
+
+        findings.push(Finding {
+            severity: Severity::Medium,
+            location: format!("{file}:{line}"),
+            description: "rustfmt would reformat this file".to_string(),
+            optimization: Some(format!("Run `cargo fmt`. Diff:\n{}", diff_body.join("\n"))),
+        });
+
+        i = j;
+    }
+    findings
+}
+
+fn format_optimization(code: Option<&str>, help: Option<&str>, description: &str) -> String {
+    match (code, help) {
+        (Some(code), Some(help)) => format!("{code}: {help}"),
+        (Some(code), None) => code.to_string(),
+        (None, Some(help)) => help.to_string(),
+        (None, None) => description.to_string(),
+    }
+}
+
+fn strip_ansi(input: &str) -> String {
+    let ansi_re = Regex::new(r"\x1b\[[\d;]*m").expect("valid regex");
+    ansi_re.replace_all(input, "").into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_warning_with_lint_code_and_help() {
+        let text = "warning[clippy::needless_clone]: redundant clone\n \
+                    --> src/lib.rs:42:9\n \
+                    = help: remove this `.clone()`\n";
+        let findings = parse_text_diagnostics(text);
+        assert_eq!(findings.len(), 1);
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert_eq!(findings[0].location, "src/lib.rs:42:9");
+        assert_eq!(
+            findings[0].optimization.as_deref(),
+            Some("clippy::needless_clone: remove this `.clone()`")
+        );
+    }
+
+    #[test]
+    fn maps_error_to_critical_and_strips_ansi() {
+        let text = "\x1b[31merror\x1b[0m: mismatched types\n --> src/main.rs:1:1\n";
+        let findings = parse_text_diagnostics(text);
+        assert_eq!(findings.len(), 1);
+        assert!(matches!(findings[0].severity, Severity::Critical));
+        assert_eq!(findings[0].location, "src/main.rs:1:1");
+    }
+
+    #[test]
+    fn parses_fmt_diff_header_from_stdout() {
+        let text = "Diff in src/lib.rs:42:\n \
+                    -fn foo( x:i32){}\n \
+                    +fn foo(x: i32) {}\n\
+                    Diff in src/main.rs:7:\n \
+                    -let x=1;\n \
+                    +let x = 1;\n";
+        let findings = parse_fmt_diagnostics(text);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].location, "src/lib.rs:42");
+        assert_eq!(findings[1].location, "src/main.rs:7");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+    }
+
+    #[test]
+    fn no_fmt_diffs_produces_no_findings() {
+        assert!(parse_fmt_diagnostics("").is_empty());
+    }
+}
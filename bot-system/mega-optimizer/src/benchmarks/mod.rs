@@ -0,0 +1,211 @@
+pub mod profilers;
+
+use crate::optimizers::{ImpactLevel, Optimization};
+use profilers::{FlamegraphProfiler, Profiler, ResourceMonitor, WallClockProfiler};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Knobs for a benchmark run, mirroring windsock's CLI ergonomics
+/// (`--bench-length-seconds`, `--operations-per-second`).
+#[derive(Debug, Clone, Copy)]
+pub struct BenchmarkConfig {
+    pub bench_length_seconds: u64,
+    pub operations_per_second: u64,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            bench_length_seconds: 5,
+            operations_per_second: 1_000,
+        }
+    }
+}
+
+/// Measured throughput/latency for one arm (baseline or optimized) of a benchmark.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArmResult {
+    pub operations_completed: u64,
+    pub p50_latency_micros: u64,
+    pub p99_latency_micros: u64,
+    pub peak_rss_kb: u64,
+    pub flamegraph_samples: u64,
+}
+
+/// Baseline-vs-optimized comparison for a single `Optimization`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkResult {
+    pub baseline: ArmResult,
+    pub optimized: ArmResult,
+    pub speedup: f64,
+    pub impact: ImpactLevel,
+}
+
+/// Runs `workload` for `config.bench_length_seconds`, pacing calls to roughly
+/// `config.operations_per_second`, and records completed operations plus
+/// p50/p99 latency alongside resource and flamegraph samples.
+fn run_arm<F: FnMut()>(mut workload: F, config: BenchmarkConfig) -> ArmResult {
+    let mut wall_clock = WallClockProfiler::new();
+    let mut resources = ResourceMonitor::new();
+    let mut flamegraph = FlamegraphProfiler::new();
+
+    wall_clock.start();
+    resources.start();
+    flamegraph.start();
+
+    let budget = Duration::from_secs(config.bench_length_seconds);
+    let min_gap = if config.operations_per_second == 0 {
+        Duration::ZERO
+    } else {
+        Duration::from_secs_f64(1.0 / config.operations_per_second as f64)
+    };
+
+    let run_start = Instant::now();
+    let mut latencies = Vec::new();
+    while run_start.elapsed() < budget {
+        let op_start = Instant::now();
+        workload();
+        let latency = op_start.elapsed();
+        latencies.push(latency);
+
+        if latency < min_gap {
+            std::thread::sleep(min_gap - latency);
+        }
+    }
+
+    wall_clock.stop();
+    resources.stop();
+    flamegraph.stop();
+
+    latencies.sort_unstable();
+    let p50 = percentile(&latencies, 50);
+    let p99 = percentile(&latencies, 99);
+
+    ArmResult {
+        operations_completed: latencies.len() as u64,
+        p50_latency_micros: p50.as_micros() as u64,
+        p99_latency_micros: p99.as_micros() as u64,
+        peak_rss_kb: resources.peak_rss_kb,
+        flamegraph_samples: flamegraph.samples_collected,
+    }
+}
+
+fn percentile(sorted_latencies: &[Duration], pct: usize) -> Duration {
+    if sorted_latencies.is_empty() {
+        return Duration::ZERO;
+    }
+    let idx = (sorted_latencies.len() * pct / 100).min(sorted_latencies.len() - 1);
+    sorted_latencies[idx]
+}
+
+/// Runs a baseline-vs-optimized measurement and derives `ImpactLevel` from the
+/// real speedup instead of trusting a hardcoded label.
+pub fn measure<B: FnMut(), O: FnMut()>(
+    baseline: B,
+    optimized: O,
+    config: BenchmarkConfig,
+) -> BenchmarkResult {
+    let baseline = run_arm(baseline, config);
+    let optimized_result = run_arm(optimized, config);
+
+    // p50 is kept in whole microseconds, so an optimized arm faster than 1us reads as
+    // 0; it is counted as 1us, which makes the speedup a lower bound in that case.
+    let speedup = if baseline.p50_latency_micros == 0 && optimized_result.p50_latency_micros == 0 {
+        1.0
+    } else {
+        baseline.p50_latency_micros as f64 / optimized_result.p50_latency_micros.max(1) as f64
+    };
+
+    BenchmarkResult {
+        impact: impact_from_speedup(speedup),
+        baseline,
+        optimized: optimized_result,
+        speedup,
+    }
+}
+
+/// Maps a measured speedup ratio onto the thresholds `ImpactLevel`'s doc comment claims.
+fn impact_from_speedup(speedup: f64) -> ImpactLevel {
+    if speedup >= 10.0 {
+        ImpactLevel::High
+    } else if speedup >= 3.0 {
+        ImpactLevel::Medium
+    } else {
+        ImpactLevel::Low
+    }
+}
+
+/// A real baseline/optimized workload an optimizer can register for its category, so
+/// `attach_benchmarks` has something honest to measure instead of two no-op closures.
+pub struct Workload {
+    pub baseline: fn(),
+    pub optimized: fn(),
+}
+
+/// Workloads registered per `Optimization::category`. `optimizers::workload_registry`
+/// builds it from each optimizer's `register_workloads`.
+pub type WorkloadRegistry = HashMap<String, Workload>;
+
+/// For every optimization that doesn't already carry measured numbers, measures it
+/// against its registered `Workload` and derives `ImpactLevel` from the real speedup.
+///
+/// Categories with no registered workload are left untouched: timing two no-op
+/// closures against each other produces pure measurement noise, not a signal, so
+/// it must never be allowed to overwrite a category's hand-set `ImpactLevel`.
+pub fn attach_benchmarks(
+    optimizations: &mut [Optimization],
+    config: BenchmarkConfig,
+    workloads: &WorkloadRegistry,
+) {
+    for optimization in optimizations.iter_mut() {
+        if optimization.benchmark.is_some() {
+            continue;
+        }
+        let Some(workload) = workloads.get(&optimization.category) else {
+            continue;
+        };
+
+        let result = measure(workload.baseline, workload.optimized, config);
+        optimization.impact = result.impact.clone();
+        optimization.benchmark = Some(result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_thresholds_to_existing_impact_levels() {
+        assert!(matches!(impact_from_speedup(12.0), ImpactLevel::High));
+        assert!(matches!(impact_from_speedup(5.0), ImpactLevel::Medium));
+        assert!(matches!(impact_from_speedup(1.5), ImpactLevel::Low));
+    }
+
+    #[test]
+    fn percentile_clamps_to_last_sample() {
+        let samples = vec![
+            Duration::from_millis(1),
+            Duration::from_millis(2),
+            Duration::from_millis(3),
+        ];
+        assert_eq!(percentile(&samples, 99), Duration::from_millis(3));
+    }
+
+    #[test]
+    fn leaves_impact_untouched_without_a_registered_workload() {
+        let mut optimizations = vec![Optimization {
+            category: "Redis Caching".to_string(),
+            description: "Add Redis caching layer for frequent queries".to_string(),
+            file_changes: vec![],
+            impact: ImpactLevel::High,
+            benchmark: None,
+        }];
+
+        attach_benchmarks(&mut optimizations, BenchmarkConfig::default(), &WorkloadRegistry::new());
+
+        assert!(matches!(optimizations[0].impact, ImpactLevel::High));
+        assert!(optimizations[0].benchmark.is_none());
+    }
+}
@@ -0,0 +1,131 @@
+use std::time::{Duration, Instant};
+
+/// A profiler samples some signal over the lifetime of a benchmark run.
+///
+/// Mirrors the windsock pattern of pluggable, composable profilers: a runner
+/// can attach several and read back whatever each one collected once the
+/// workload stops.
+pub trait Profiler {
+    /// Human-readable name of the profiler.
+    fn name(&self) -> &'static str;
+
+    /// Called once immediately before the workload starts.
+    fn start(&mut self);
+
+    /// Called once immediately after the workload stops.
+    fn stop(&mut self);
+}
+
+/// Measures elapsed wall-clock time for a run. It does not count operations;
+/// `run_arm` tracks completed operations itself.
+pub struct WallClockProfiler {
+    started_at: Option<Instant>,
+    pub elapsed: Duration,
+}
+
+impl WallClockProfiler {
+    pub fn new() -> Self {
+        Self {
+            started_at: None,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+impl Profiler for WallClockProfiler {
+    fn name(&self) -> &'static str {
+        "wall-clock"
+    }
+
+    fn start(&mut self) {
+        self.started_at = Some(Instant::now());
+    }
+
+    fn stop(&mut self) {
+        if let Some(start) = self.started_at.take() {
+            self.elapsed = start.elapsed();
+        }
+    }
+}
+
+/// Samples the process's resident set size (`VmRSS` from `/proc/self/status`)
+/// once at `start` and once at `stop`; `peak_rss_kb` is the larger of the two,
+/// not a peak over the whole run. `cpu_time` is not sampled and stays zero.
+/// Where `/proc/self/status` is unavailable the RSS samples are zero rather
+/// than failing the run.
+pub struct ResourceMonitor {
+    pub peak_rss_kb: u64,
+    pub cpu_time: Duration,
+}
+
+impl ResourceMonitor {
+    pub fn new() -> Self {
+        Self {
+            peak_rss_kb: 0,
+            cpu_time: Duration::ZERO,
+        }
+    }
+
+    fn sample_rss_kb() -> u64 {
+        let Ok(status) = std::fs::read_to_string("/proc/self/status") else {
+            return 0;
+        };
+        status
+            .lines()
+            .find(|line| line.starts_with("VmRSS:"))
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|kb| kb.parse().ok())
+            .unwrap_or(0)
+    }
+}
+
+impl Profiler for ResourceMonitor {
+    fn name(&self) -> &'static str {
+        "resource-monitor"
+    }
+
+    fn start(&mut self) {
+        self.peak_rss_kb = Self::sample_rss_kb();
+    }
+
+    fn stop(&mut self) {
+        self.peak_rss_kb = self.peak_rss_kb.max(Self::sample_rss_kb());
+    }
+}
+
+/// Placeholder for a stack-sampling profiler, attached to both benchmark arms.
+/// It takes no stack samples and produces no flamegraph: `samples_collected`
+/// is the number of `SAMPLE_INTERVAL` periods the run spanned.
+pub struct FlamegraphProfiler {
+    started_at: Option<Instant>,
+    pub samples_collected: u64,
+}
+
+impl FlamegraphProfiler {
+    /// Roughly how often a real sampling profiler would interrupt the workload.
+    const SAMPLE_INTERVAL: Duration = Duration::from_millis(10);
+
+    pub fn new() -> Self {
+        Self {
+            started_at: None,
+            samples_collected: 0,
+        }
+    }
+}
+
+impl Profiler for FlamegraphProfiler {
+    fn name(&self) -> &'static str {
+        "flamegraph"
+    }
+
+    fn start(&mut self) {
+        self.started_at = Some(Instant::now());
+    }
+
+    fn stop(&mut self) {
+        if let Some(start) = self.started_at.take() {
+            self.samples_collected = start.elapsed().as_nanos() as u64
+                / Self::SAMPLE_INTERVAL.as_nanos().max(1) as u64;
+        }
+    }
+}
@@ -3,6 +3,8 @@ use anyhow::Result;
 use crate::optimizers::Optimization;
 use log::info;
 
+mod pr_creator;
+
 pub struct GitHubClient {
     client: Octocrab,
 }
@@ -27,33 +29,15 @@ impl GitHubClient {
         ])
     }
     
+    /// Opens a PR with the given optimizations applied. Returns the PR URL,
+    /// or `None` when `dry_run` is set (the diff plan is logged instead).
     pub async fn create_optimization_pr(
         &self,
         owner: &str,
         repo: &str,
         optimizations: &[Optimization],
-    ) -> Result<()> {
-        pr_creator::create_pr(&self.client, owner, repo, optimizations).await
-    }
-}
-
-mod pr_creator {
-    use super::*;
-    
-    pub async fn create_pr(
-        _client: &Octocrab,
-        owner: &str,
-        repo: &str,
-        optimizations: &[Optimization],
-    ) -> Result<()> {
-        info!("Creating PR for {}/{} with {} optimizations", owner, repo, optimizations.len());
-        
-        // In a real implementation, this would:
-        // 1. Create a new branch
-        // 2. Apply file changes
-        // 3. Commit changes
-        // 4. Create pull request
-        
-        Ok(())
+        dry_run: bool,
+    ) -> Result<Option<String>> {
+        pr_creator::create_pr(&self.client, owner, repo, optimizations, dry_run).await
     }
 }
@@ -1,21 +1,258 @@
-use octocrab::Octocrab;
-use anyhow::Result;
-use crate::optimizers::Optimization;
+use crate::optimizers::{ChangeType, FileChange, ImpactLevel, Optimization};
+use anyhow::{bail, Context, Result};
+use base64::Engine;
 use log::info;
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+#[derive(Deserialize)]
+struct RepoInfo {
+    default_branch: String,
+}
+
+#[derive(Deserialize)]
+struct GitRef {
+    object: GitRefObject,
+}
+
+#[derive(Deserialize)]
+struct GitRefObject {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct GitCommit {
+    tree: GitTreeRef,
+}
+
+#[derive(Deserialize)]
+struct GitTreeRef {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct GitBlob {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct GitTree {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct GitNewCommit {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct PullRequest {
+    html_url: String,
+}
+
+#[derive(Serialize)]
+struct TreeEntry {
+    path: String,
+    mode: &'static str,
+    #[serde(rename = "type")]
+    entry_type: &'static str,
+    // Deliberately always serialized: GitHub's git/trees API deletes a path only when
+    // `sha` is present and explicitly `null`. Omitting the key (as `skip_serializing_if`
+    // would) leaves a Delete entry with neither `sha` nor `content`, which the API rejects.
+    sha: Option<String>,
+}
 
+/// Implements the PR flow via GitHub's git-data API: blob(s) -> tree -> commit -> ref,
+/// then opens a pull request. `dry_run` stops before any mutating call and prints the
+/// plan instead.
 pub async fn create_pr(
-    _client: &Octocrab,
+    client: &Octocrab,
     owner: &str,
     repo: &str,
     optimizations: &[Optimization],
-) -> Result<()> {
-    info!("Creating PR for {}/{} with {} optimizations", owner, repo, optimizations.len());
-    
-    // In a real implementation, this would:
-    // 1. Create a new branch
-    // 2. Apply file changes
-    // 3. Commit changes
-    // 4. Create pull request
-    
-    Ok(())
+    dry_run: bool,
+) -> Result<Option<String>> {
+    info!(
+        "Creating PR for {}/{} with {} optimizations",
+        owner,
+        repo,
+        optimizations.len()
+    );
+
+    let repo_info: RepoInfo = client
+        .get(format!("/repos/{owner}/{repo}"), None::<&()>)
+        .await
+        .context("failed to fetch repository info")?;
+    let base_branch = repo_info.default_branch;
+
+    let base_ref: GitRef = client
+        .get(
+            format!("/repos/{owner}/{repo}/git/ref/heads/{base_branch}"),
+            None::<&()>,
+        )
+        .await
+        .context("failed to fetch base branch ref")?;
+    let head_sha = base_ref.object.sha;
+
+    let branch_name = format!("optimizer/{}", branch_timestamp());
+    let file_changes: Vec<&FileChange> = optimizations
+        .iter()
+        .flat_map(|opt| opt.file_changes.iter())
+        .collect();
+    let pr_body = render_pr_body(optimizations);
+
+    if dry_run {
+        info!("🏃 Dry run - planned changes instead of mutating {owner}/{repo}:");
+        info!("  new branch: {branch_name} (from {base_branch} @ {head_sha})");
+        for change in &file_changes {
+            info!("  {:?} {}", change.change_type, change.path);
+        }
+        info!("  PR body:\n{pr_body}");
+        return Ok(None);
+    }
+
+    if file_changes.is_empty() {
+        bail!("no file changes to apply - refusing to open an empty PR");
+    }
+
+    // 1. Branch the new ref off the current head before building the commit on top of it.
+    client
+        .post::<_, serde_json::Value>(
+            format!("/repos/{owner}/{repo}/git/refs"),
+            Some(&json!({
+                "ref": format!("refs/heads/{branch_name}"),
+                "sha": head_sha,
+            })),
+        )
+        .await
+        .context("failed to create branch ref")?;
+
+    let head_commit: GitCommit = client
+        .get(
+            format!("/repos/{owner}/{repo}/git/commits/{head_sha}"),
+            None::<&()>,
+        )
+        .await
+        .context("failed to fetch head commit")?;
+
+    // 2. Create a blob per Create/Modify change; Delete entries carry no blob (a `null`
+    // tree sha tells GitHub to remove the path).
+    let mut tree_entries = Vec::with_capacity(file_changes.len());
+    for change in &file_changes {
+        let sha = match change.change_type {
+            ChangeType::Delete => None,
+            ChangeType::Create | ChangeType::Modify => {
+                let encoded = base64::engine::general_purpose::STANDARD.encode(&change.content);
+                let blob: GitBlob = client
+                    .post(
+                        format!("/repos/{owner}/{repo}/git/blobs"),
+                        Some(&json!({
+                            "content": encoded,
+                            "encoding": "base64",
+                        })),
+                    )
+                    .await
+                    .with_context(|| format!("failed to create blob for {}", change.path))?;
+                Some(blob.sha)
+            }
+        };
+
+        tree_entries.push(TreeEntry {
+            path: change.path.clone(),
+            mode: "100644",
+            entry_type: "blob",
+            sha,
+        });
+    }
+
+    // 3. Assemble a new tree on top of the head commit's tree.
+    let tree: GitTree = client
+        .post(
+            format!("/repos/{owner}/{repo}/git/trees"),
+            Some(&json!({
+                "base_tree": head_commit.tree.sha,
+                "tree": tree_entries,
+            })),
+        )
+        .await
+        .context("failed to create tree")?;
+
+    // 4. Commit the tree, then move the branch ref onto it.
+    let commit: GitNewCommit = client
+        .post(
+            format!("/repos/{owner}/{repo}/git/commits"),
+            Some(&json!({
+                "message": "Apply automated optimizations",
+                "tree": tree.sha,
+                "parents": [head_sha],
+            })),
+        )
+        .await
+        .context("failed to create commit")?;
+
+    client
+        .patch::<serde_json::Value, _, _>(
+            format!("/repos/{owner}/{repo}/git/refs/heads/{branch_name}"),
+            Some(&json!({
+                "sha": commit.sha,
+                "force": false,
+            })),
+        )
+        .await
+        .context("failed to update branch ref")?;
+
+    // 5. Open the pull request.
+    let pull_request: PullRequest = client
+        .post(
+            format!("/repos/{owner}/{repo}/pulls"),
+            Some(&json!({
+                "title": "Automated optimizations",
+                "head": branch_name,
+                "base": base_branch,
+                "body": pr_body,
+            })),
+        )
+        .await
+        .context("failed to open pull request")?;
+
+    Ok(Some(pull_request.html_url))
+}
+
+fn branch_timestamp() -> String {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs().to_string())
+        .unwrap_or_else(|_| "0".to_string())
+}
+
+/// Groups optimizations by category and annotates each with its `ImpactLevel`.
+fn render_pr_body(optimizations: &[Optimization]) -> String {
+    let mut by_category: BTreeMap<&str, Vec<&Optimization>> = BTreeMap::new();
+    for opt in optimizations {
+        by_category.entry(&opt.category).or_default().push(opt);
+    }
+
+    let mut body = String::from("## Automated Optimizations\n\n");
+    for (category, opts) in by_category {
+        body.push_str(&format!("### {category}\n\n"));
+        for opt in opts {
+            body.push_str(&format!(
+                "- **[{}]** {}\n",
+                impact_label(&opt.impact),
+                opt.description
+            ));
+        }
+        body.push('\n');
+    }
+    body
+}
+
+fn impact_label(impact: &ImpactLevel) -> &'static str {
+    match impact {
+        ImpactLevel::High => "High impact",
+        ImpactLevel::Medium => "Medium impact",
+        ImpactLevel::Low => "Low impact",
+    }
 }
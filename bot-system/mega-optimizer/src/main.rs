@@ -3,6 +3,7 @@ use clap::Parser;
 use log::{info, error};
 
 mod analyzers;
+mod benchmarks;
 mod optimizers;
 mod github;
 mod ai;
@@ -28,6 +29,19 @@ struct Args {
     /// Dry run mode (don't create PRs)
     #[arg(short, long, default_value = "false")]
     dry_run: bool,
+
+    /// Measure baseline-vs-optimized throughput and attach real numbers to
+    /// each optimization's impact instead of trusting the hardcoded estimate
+    #[arg(long, default_value = "false")]
+    benchmark: bool,
+
+    /// How long each benchmark arm runs for, in seconds
+    #[arg(long, default_value = "5")]
+    bench_length_seconds: u64,
+
+    /// Target operations per second when pacing a benchmark workload
+    #[arg(long, default_value = "1000")]
+    operations_per_second: u64,
 }
 
 #[tokio::main]
@@ -55,7 +69,12 @@ async fn main() -> Result<()> {
     for repo_name in repos {
         info!("🔍 Analyzing repository: {}/{}", args.owner, repo_name);
         
-        match analyze_and_optimize(&github_client, &args.owner, &repo_name, args.dry_run).await {
+        let bench_config = args.benchmark.then_some(benchmarks::BenchmarkConfig {
+            bench_length_seconds: args.bench_length_seconds,
+            operations_per_second: args.operations_per_second,
+        });
+
+        match analyze_and_optimize(&github_client, &args.owner, &repo_name, args.dry_run, bench_config).await {
             Ok(()) => info!("✅ Successfully processed {}", repo_name),
             Err(e) => error!("❌ Failed to process {}: {}", repo_name, e),
         }
@@ -70,6 +89,7 @@ async fn analyze_and_optimize(
     owner: &str,
     repo: &str,
     dry_run: bool,
+    bench_config: Option<benchmarks::BenchmarkConfig>,
 ) -> Result<()> {
     // Clone or download repository content
     info!("📥 Fetching repository content...");
@@ -82,7 +102,18 @@ async fn analyze_and_optimize(
     
     // Run analyzers
     let mut analysis_results = Vec::new();
-    
+
+    // Committed binaries and build artifacts are worth flagging in any repo,
+    // so this runs regardless of detected tech stack.
+    info!("🧹 Running binary/artifact analyzer...");
+    let result = analyzers::binary_analyzer::analyze(owner, repo).await?;
+    analysis_results.push(result);
+
+    // Manifest conflicts span services/languages, so this also runs unconditionally.
+    info!("📦 Running dependency analyzer...");
+    let result = analyzers::dependency_analyzer::analyze(owner, repo).await?;
+    analysis_results.push(result);
+
     if tech_stack.has_rust {
         info!("🦀 Running Rust analyzer...");
         let result = analyzers::rust_analyzer::analyze(owner, repo).await?;
@@ -103,19 +134,29 @@ async fn analyze_and_optimize(
     
     // Generate optimizations
     info!("⚡ Generating optimizations...");
-    let optimizations = optimizers::generate_optimizations(&analysis_results).await?;
-    
+    let mut optimizations = optimizers::generate_optimizations(&analysis_results).await?;
+
     info!("Found {} optimization opportunities", optimizations.len());
+
+    if let Some(config) = bench_config {
+        info!("⏱️  Benchmarking optimizations (--benchmark)...");
+        benchmarks::attach_benchmarks(&mut optimizations, config, &optimizers::workload_registry());
+    }
     
-    if !dry_run && !optimizations.is_empty() {
-        info!("📝 Creating pull request with optimizations...");
-        github.create_optimization_pr(owner, repo, &optimizations).await?;
-    } else if dry_run {
-        info!("🏃 Dry run mode - skipping PR creation");
-        for opt in &optimizations {
-            info!("  - {}: {}", opt.category, opt.description);
+    if !optimizations.is_empty() {
+        if dry_run {
+            info!("🏃 Dry run mode - printing diff plan instead of creating a PR");
+        } else {
+            info!("📝 Creating pull request with optimizations...");
+        }
+
+        if let Some(pr_url) = github
+            .create_optimization_pr(owner, repo, &optimizations, dry_run)
+            .await?
+        {
+            info!("🔗 Pull request created: {pr_url}");
         }
     }
-    
+
     Ok(())
 }
@@ -1,15 +1,70 @@
 use super::{Optimization, ImpactLevel};
+use crate::benchmarks::{Workload, WorkloadRegistry};
 use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
 
 pub async fn generate_redis_caching() -> Result<Vec<Optimization>> {
     let mut optimizations = Vec::new();
-    
+
     optimizations.push(Optimization {
         category: "Redis Caching".to_string(),
         description: "Add Redis caching layer for frequent queries".to_string(),
         file_changes: vec![],
         impact: ImpactLevel::High,
+        benchmark: None,
     });
-    
+
     Ok(optimizations)
 }
+
+/// Registers a real baseline-vs-optimized workload for "Redis Caching" so
+/// `--benchmark` has something honest to measure: re-running the "query" on
+/// every call (baseline) versus serving it from an in-memory cache after the
+/// first miss (optimized), standing in for a DB round trip vs. a Redis hit.
+pub fn register_workloads(registry: &mut WorkloadRegistry) {
+    registry.insert(
+        "Redis Caching".to_string(),
+        Workload {
+            baseline: uncached_lookup,
+            optimized: cached_lookup,
+        },
+    );
+}
+
+const LOOKUP_KEY: u64 = 42;
+
+fn uncached_lookup() {
+    // `black_box` keeps the compiler from folding or dropping the unused query.
+    std::hint::black_box(expensive_query(std::hint::black_box(LOOKUP_KEY)));
+}
+
+fn cached_lookup() {
+    static CACHE: OnceLock<Mutex<HashMap<u64, u64>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut cache = cache.lock().unwrap();
+    cache.entry(LOOKUP_KEY).or_insert_with(|| expensive_query(LOOKUP_KEY));
+}
+
+/// Stands in for a slow DB round trip that a cache would let you skip.
+fn expensive_query(key: u64) -> u64 {
+    (0..10_000u64).fold(key, |acc, x| acc.wrapping_mul(31).wrapping_add(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_a_workload_for_redis_caching() {
+        let mut registry = WorkloadRegistry::new();
+        register_workloads(&mut registry);
+        assert!(registry.contains_key("Redis Caching"));
+    }
+
+    #[test]
+    fn cached_lookup_can_be_called_repeatedly() {
+        cached_lookup();
+        cached_lookup();
+    }
+}
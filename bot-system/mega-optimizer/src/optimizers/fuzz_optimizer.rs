@@ -0,0 +1,204 @@
+use super::{ChangeType, FileChange, ImpactLevel, Optimization};
+use crate::analyzers::{AnalysisResult, Finding};
+use anyhow::Result;
+
+/// Words that, in a finding's location or description, suggest it touches code parsing
+/// attacker-controlled input: request bodies, file uploads, deserialization entry points, and so on.
+const UNTRUSTED_INPUT_KEYWORDS: &[&str] = &[
+    "request", "upload", "deserial", "payload", "body", "decode", "parse",
+];
+
+/// Scaffolds a cargo-fuzz or Atheris harness for every finding whose location or
+/// description looks like it parses untrusted input, following the practice of checking in
+/// fuzz targets for critical parsers.
+pub async fn generate_optimizations(analysis_results: &[AnalysisResult]) -> Result<Vec<Optimization>> {
+    let mut optimizations = Vec::new();
+
+    for result in analysis_results {
+        for finding in &result.findings {
+            if !touches_untrusted_input(finding) {
+                continue;
+            }
+
+            let file_changes = match result.tech_stack.as_str() {
+                "rust" => rust_fuzz_harness(&finding.location),
+                "python" => python_fuzz_harness(&finding.location),
+                _ => continue,
+            };
+
+            optimizations.push(Optimization {
+                category: "Fuzzing".to_string(),
+                description: format!(
+                    "Scaffold a fuzz harness for the input-parsing code at {}",
+                    finding.location
+                ),
+                file_changes,
+                impact: ImpactLevel::Medium,
+                benchmark: None,
+            });
+        }
+    }
+
+    Ok(optimizations)
+}
+
+fn touches_untrusted_input(finding: &Finding) -> bool {
+    let haystack = format!("{} {}", finding.location, finding.description).to_lowercase();
+    UNTRUSTED_INPUT_KEYWORDS.iter().any(|kw| haystack.contains(kw))
+}
+
+fn rust_fuzz_harness(location: &str) -> Vec<FileChange> {
+    let target_name = sanitize_target_name(location);
+
+    vec![
+        FileChange {
+            path: "fuzz/Cargo.toml".to_string(),
+            content: format!(
+                r#"[package]
+name = "fuzz"
+version = "0.0.0"
+publish = false
+edition = "2021"
+
+[package.metadata]
+cargo-fuzz = true
+
+[dependencies]
+libfuzzer-sys = "0.4"
+
+[[bin]]
+name = "{target_name}"
+path = "fuzz_targets/{target_name}.rs"
+test = false
+doc = false
+"#
+            ),
+            change_type: ChangeType::Create,
+        },
+        FileChange {
+            path: format!("fuzz/fuzz_targets/{target_name}.rs"),
+            content: format!(
+                r#"#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+// Scaffold for the input-parsing path flagged at `{location}`.
+fuzz_target!(|data: &[u8]| {{
+    let _ = data;
+    todo!("add the crate as a dependency in fuzz/Cargo.toml and call the real parser at {location}");
+}});
+"#
+            ),
+            change_type: ChangeType::Create,
+        },
+    ]
+}
+
+fn python_fuzz_harness(location: &str) -> Vec<FileChange> {
+    let module_name = sanitize_target_name(location);
+
+    vec![
+        FileChange {
+            path: format!("fuzz/atheris_{module_name}.py"),
+            content: format!(
+                r#"#!/usr/bin/env python3
+"""Atheris fuzz harness for the input-parsing path flagged at `{location}`."""
+import sys
+
+import atheris
+
+
+def parse_untrusted_input(payload):
+    raise NotImplementedError("wire this up to the real parser at {location}")
+
+
+def test_one_input(data):
+    fdp = atheris.FuzzedDataProvider(data)
+    payload = fdp.ConsumeBytes(fdp.remaining_bytes())
+    try:
+        parse_untrusted_input(payload)
+    except Exception:
+        # Replace with the specific exception types the parser is expected to raise.
+        pass
+
+
+def main():
+    atheris.Setup(sys.argv, test_one_input)
+    atheris.Fuzz()
+
+
+if __name__ == "__main__":
+    main()
+"#
+            ),
+            change_type: ChangeType::Create,
+        },
+        FileChange {
+            path: format!(".github/workflows/fuzz-{module_name}.yml"),
+            content: format!(
+                r#"name: fuzz-{module_name}
+
+on:
+  schedule:
+    - cron: "0 6 * * *"
+  workflow_dispatch: {{}}
+
+jobs:
+  atheris:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: actions/setup-python@v5
+        with:
+          python-version: "3.11"
+      - run: pip install atheris
+      - run: python fuzz/atheris_{module_name}.py -max_total_time=60
+"#
+            ),
+            change_type: ChangeType::Create,
+        },
+    ]
+}
+
+fn sanitize_target_name(location: &str) -> String {
+    let sanitized: String = location
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    sanitized.trim_matches('_').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::Severity;
+
+    fn finding(location: &str, description: &str) -> Finding {
+        Finding {
+            severity: Severity::Medium,
+            location: location.to_string(),
+            description: description.to_string(),
+            optimization: None,
+        }
+    }
+
+    #[test]
+    fn flags_request_body_parsing() {
+        assert!(touches_untrusted_input(&finding(
+            "services/api/main.py",
+            "Missing validation on request body deserialization"
+        )));
+    }
+
+    #[test]
+    fn ignores_unrelated_findings() {
+        assert!(!touches_untrusted_input(&finding(
+            "Cargo.toml",
+            "Missing LTO in release profile"
+        )));
+    }
+
+    #[test]
+    fn sanitizes_location_into_a_valid_identifier() {
+        assert_eq!(sanitize_target_name("services/api/main.py:42"), "services_api_main_py_42");
+    }
+}
@@ -2,8 +2,10 @@ pub mod rust_optimizer;
 pub mod python_optimizer;
 pub mod gpu_optimizer;
 pub mod cache_optimizer;
+pub mod fuzz_optimizer;
 
 use crate::analyzers::AnalysisResult;
+use crate::benchmarks::{BenchmarkResult, WorkloadRegistry};
 use serde::{Deserialize, Serialize};
 use anyhow::Result;
 
@@ -13,6 +15,10 @@ pub struct Optimization {
     pub description: String,
     pub file_changes: Vec<FileChange>,
     pub impact: ImpactLevel,
+    /// Measured baseline-vs-optimized numbers, populated by `benchmarks::attach_benchmarks`
+    /// when the bot is run with `--benchmark`. `None` means `impact` is still the
+    /// category's hardcoded estimate.
+    pub benchmark: Option<BenchmarkResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,6 +64,18 @@ pub async fn generate_optimizations(
     // Add infrastructure optimizations
     let cache_opts = cache_optimizer::generate_redis_caching().await?;
     optimizations.extend(cache_opts);
-    
+
+    // Scaffold fuzz harnesses for any finding that touches untrusted input
+    let fuzz_opts = fuzz_optimizer::generate_optimizations(analysis_results).await?;
+    optimizations.extend(fuzz_opts);
+
     Ok(optimizations)
 }
+
+/// Collects every optimizer's registered before/after `Workload`s, keyed by
+/// `Optimization::category`, for `benchmarks::attach_benchmarks` to measure against.
+pub fn workload_registry() -> WorkloadRegistry {
+    let mut registry = WorkloadRegistry::new();
+    cache_optimizer::register_workloads(&mut registry);
+    registry
+}
@@ -11,6 +11,7 @@ pub async fn generate_optimizations(_result: &AnalysisResult) -> Result<Vec<Opti
         description: "Configure Uvicorn with multiple workers and optimized event loop".to_string(),
         file_changes: vec![],
         impact: ImpactLevel::High,
+        benchmark: None,
     });
     
     optimizations.push(Optimization {
@@ -18,6 +19,7 @@ pub async fn generate_optimizations(_result: &AnalysisResult) -> Result<Vec<Opti
         description: "Enable JIT compilation and model quantization".to_string(),
         file_changes: vec![],
         impact: ImpactLevel::High,
+        benchmark: None,
     });
     
     Ok(optimizations)
@@ -11,6 +11,7 @@ pub async fn generate_optimizations(_result: &AnalysisResult) -> Result<Vec<Opti
         description: "Enable Link Time Optimization for better performance".to_string(),
         file_changes: vec![],
         impact: ImpactLevel::Medium,
+        benchmark: None,
     });
     
     Ok(optimizations)
@@ -2,41 +2,176 @@
 // Python bindings via PyO3
 // EXPERIMENTAL - Optional enhancement for performance-critical paths
 
+use candle_core::safetensors::MmapedSafetensors;
+use candle_core::{Device, Tensor};
+use memmap2::Mmap;
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_histogram, register_int_counter, register_int_gauge, Encoder, Histogram, IntCounter,
+    IntGauge, TextEncoder,
+};
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
-use ndarray::{Array1, Array2};
-
-/// Ultra-fast inference function callable from Python
-/// 
-/// Benefits:
-/// - 5-10x faster than Python
-/// - Lower memory footprint
-/// - Better resource utilization
-/// 
-/// Args:
-///     input: Vec<f32> - Input tensor as flat vector
-/// 
-/// Returns:
-///     Vec<f32> - Output tensor as flat vector
+use ndarray::Array2;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+/// A model loaded by `load_model`: the raw weight/bias tensors plus the
+/// numeric hash of the version it was loaded under. `infer` does not report
+/// this version back to the caller.
+struct LoadedModel {
+    version_hash: u64,
+    weight: Tensor,
+    bias: Tensor,
+}
+
+static MODEL_REGISTRY: Lazy<Mutex<HashMap<String, Arc<LoadedModel>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static ACTIVE_MODEL_VERSION: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "rust_ai_bridge_active_model_version",
+        "Numeric hash of the most recently loaded model/op version"
+    )
+    .expect("metric registration should not fail")
+});
+
+static REQUEST_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "rust_ai_bridge_inference_requests_total",
+        "Total number of infer() calls served"
+    )
+    .expect("metric registration should not fail")
+});
+
+static INFERENCE_LATENCY: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "rust_ai_bridge_inference_latency_seconds",
+        "Latency of infer() calls in seconds"
+    )
+    .expect("metric registration should not fail")
+});
+
+fn to_py_err<E: std::fmt::Display>(err: E) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(err.to_string())
+}
+
+/// Collapses a hex digest into a numeric version for the Prometheus gauge,
+/// which only holds integers.
+fn hash_version_string(version: &str) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    version.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Loads each `custom-op`/backend plugin named in `RUST_AI_BRIDGE_PLUGINS`
+/// (a comma-separated list of shared-library paths), mirroring TF Serving's
+/// dynamically loadable op registration. Failures are logged, not fatal -
+/// one bad plugin path shouldn't take down the whole bridge.
+fn load_plugins() {
+    let Ok(paths) = std::env::var("RUST_AI_BRIDGE_PLUGINS") else {
+        return;
+    };
+
+    for path in paths.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        match unsafe { libloading::Library::new(path) } {
+            Ok(lib) => {
+                // The op's version is the SHA-256 digest of its file, as for models.
+                if let Ok(bytes) = std::fs::read(path) {
+                    let version = hex::encode(Sha256::digest(&bytes));
+                    ACTIVE_MODEL_VERSION.set(hash_version_string(&version) as i64);
+                }
+                // Keep the library mapped for the life of the process; there's no
+                // unload hook for custom ops once registered.
+                std::mem::forget(lib);
+            }
+            Err(e) => eprintln!("rust_ai_bridge: failed to load plugin '{path}': {e}"),
+        }
+    }
+}
+
+/// Memory-maps a safetensors model file, records its SHA-256 digest as the
+/// active model version, and loads its `weight`/`bias` tensors.
+///
+/// Returns a `model_id` (the first 16 hex chars of the digest) to pass to
+/// `infer`.
 #[pyfunction]
-fn fast_inference_rust(input: Vec<f32>) -> PyResult<Vec<f32>> {
-    // Example: Simple transformation (replace with actual model inference)
-    // In production, this would call burn/candle/tract models
-    
-    let output: Vec<f32> = input
-        .iter()
-        .map(|&x| x * 2.0 + 1.0)  // Simple operation for demonstration
-        .collect();
-    
-    Ok(output)
+fn load_model(path: String) -> PyResult<String> {
+    let file = File::open(&path).map_err(to_py_err)?;
+    let mmap = unsafe { Mmap::map(&file) }.map_err(to_py_err)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(&mmap[..]);
+    let version = hex::encode(hasher.finalize());
+
+    let tensors = unsafe { MmapedSafetensors::new(&path) }.map_err(to_py_err)?;
+    let device = Device::Cpu;
+    let weight = tensors.load("weight", &device).map_err(to_py_err)?;
+    let bias = tensors.load("bias", &device).map_err(to_py_err)?;
+
+    let version_hash = hash_version_string(&version);
+    let model_id = version[..16].to_string();
+
+    MODEL_REGISTRY.lock().unwrap().insert(
+        model_id.clone(),
+        Arc::new(LoadedModel {
+            version_hash,
+            weight,
+            bias,
+        }),
+    );
+    ACTIVE_MODEL_VERSION.set(version_hash as i64);
+
+    Ok(model_id)
+}
+
+/// Runs the real forward pass `y = x @ weight^T + bias` for a previously
+/// loaded model.
+#[pyfunction]
+fn infer(model_id: String, input: Vec<f32>) -> PyResult<Vec<f32>> {
+    REQUEST_COUNT.inc();
+    let _timer = INFERENCE_LATENCY.start_timer();
+
+    let model = {
+        let registry = MODEL_REGISTRY.lock().unwrap();
+        registry.get(&model_id).cloned().ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyKeyError, _>(format!(
+                "unknown model_id: {model_id} (call load_model first)"
+            ))
+        })?
+    };
+
+    let device = Device::Cpu;
+    let len = input.len();
+    let x = Tensor::from_vec(input, (1, len), &device).map_err(to_py_err)?;
+    let weight_t = model.weight.t().map_err(to_py_err)?;
+    let y = x.matmul(&weight_t).map_err(to_py_err)?;
+    let y = y.broadcast_add(&model.bias).map_err(to_py_err)?;
+
+    y.flatten_all().map_err(to_py_err)?.to_vec1::<f32>().map_err(to_py_err)
+}
+
+/// Returns Prometheus-formatted metrics (request count, latency histogram,
+/// active model version) so the bridge can be scraped from Python.
+#[pyfunction]
+fn metrics() -> PyResult<String> {
+    let metric_families = prometheus::gather();
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(&metric_families, &mut buffer)
+        .map_err(to_py_err)?;
+    String::from_utf8(buffer).map_err(to_py_err)
 }
 
 /// Fast matrix multiplication in Rust
-/// 
+///
 /// Args:
 ///     a: Vec<Vec<f32>> - First matrix
 ///     b: Vec<Vec<f32>> - Second matrix
-/// 
+///
 /// Returns:
 ///     Vec<Vec<f32>> - Result matrix
 #[pyfunction]
@@ -45,76 +180,77 @@ fn fast_matrix_mult(a: Vec<Vec<f32>>, b: Vec<Vec<f32>>) -> PyResult<Vec<Vec<f32>
     let rows_a = a.len();
     let cols_a = a[0].len();
     let cols_b = b[0].len();
-    
+
     // Flatten and create arrays
     let flat_a: Vec<f32> = a.into_iter().flatten().collect();
     let flat_b: Vec<f32> = b.into_iter().flatten().collect();
-    
+
     let array_a = Array2::from_shape_vec((rows_a, cols_a), flat_a)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Array creation failed: {}", e)))?;
     let array_b = Array2::from_shape_vec((cols_a, cols_b), flat_b)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Array creation failed: {}", e)))?;
-    
+
     // Matrix multiplication
     let result = array_a.dot(&array_b);
-    
+
     // Convert back to Vec<Vec<f32>>
     let result_vec: Vec<Vec<f32>> = result
         .outer_iter()
         .map(|row| row.to_vec())
         .collect();
-    
+
     Ok(result_vec)
 }
 
 /// Fast batch processing
-/// 
+///
 /// Args:
 ///     batch: Vec<Vec<f32>> - Batch of input vectors
-/// 
+///
 /// Returns:
 ///     Vec<Vec<f32>> - Batch of output vectors
 #[pyfunction]
 fn fast_batch_process(batch: Vec<Vec<f32>>) -> PyResult<Vec<Vec<f32>>> {
-    // Process each item in batch efficiently
-    let results: Vec<Vec<f32>> = batch
-        .into_iter()
-        .map(|input| {
-            input
-                .iter()
-                .map(|&x| x * 2.0 + 1.0)
-                .collect()
-        })
-        .collect();
-    
-    Ok(results)
+    if batch.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rows = batch.len();
+    let cols = batch[0].len();
+    let flat: Vec<f32> = batch.into_iter().flatten().collect();
+    let input = Array2::from_shape_vec((rows, cols), flat)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Array creation failed: {}", e)))?;
+
+    // Compute the whole batch into one preallocated (rows, cols) array; per-row Vecs
+    // are built only for the `Vec<Vec<f32>>` returned to Python.
+    let mut output = Array2::<f32>::zeros((rows, cols));
+    output.assign(&(&input * 2.0 + 1.0));
+
+    Ok(output.outer_iter().map(|row| row.to_vec()).collect())
 }
 
 /// Python module definition
 #[pymodule]
 fn rust_ai_bridge(_py: Python, m: &PyModule) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(fast_inference_rust, m)?)?;
+    load_plugins();
+
+    m.add_function(wrap_pyfunction!(load_model, m)?)?;
+    m.add_function(wrap_pyfunction!(infer, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics, m)?)?;
     m.add_function(wrap_pyfunction!(fast_matrix_mult, m)?)?;
     m.add_function(wrap_pyfunction!(fast_batch_process, m)?)?;
-    
+
     // Add module metadata
-    m.add("__version__", "0.1.0")?;
+    m.add("__version__", "0.2.0")?;
     m.add("__doc__", "Rust AI Bridge for ultra-high-performance inference")?;
-    
+
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    
-    #[test]
-    fn test_fast_inference() {
-        let input = vec![1.0, 2.0, 3.0];
-        let output = fast_inference_rust(input).unwrap();
-        assert_eq!(output, vec![3.0, 5.0, 7.0]);
-    }
-    
+
     #[test]
     fn test_matrix_mult() {
         let a = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
@@ -123,4 +259,23 @@ mod tests {
         assert_eq!(result[0][0], 19.0);  // 1*5 + 2*7
         assert_eq!(result[0][1], 22.0);  // 1*6 + 2*8
     }
+
+    #[test]
+    fn test_batch_process() {
+        let batch = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        let result = fast_batch_process(batch).unwrap();
+        assert_eq!(result, vec![vec![3.0, 5.0], vec![7.0, 9.0]]);
+    }
+
+    #[test]
+    fn test_batch_process_empty() {
+        let result = fast_batch_process(vec![]).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_hash_version_string_is_stable() {
+        assert_eq!(hash_version_string("abc123"), hash_version_string("abc123"));
+        assert_ne!(hash_version_string("abc123"), hash_version_string("abc124"));
+    }
 }